@@ -1,22 +1,67 @@
 #![cfg_attr(all(not(debug_assertions), target_os = "windows"), windows_subsystem = "windows")]
 
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::net::TcpListener;
 use std::path::PathBuf;
 use std::process::{Command, Child, Stdio};
+use std::sync::atomic::{AtomicU16, Ordering};
 use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 use tauri::{Manager, State};
 
+// How many recent RAPP OS log lines are kept in memory for `get_rapp_os_logs`.
+const LOG_BUFFER_CAPACITY: usize = 2000;
+
+// Range probed for a free port before each RAPP OS launch.
+const RAPP_OS_PORT_RANGE: std::ops::RangeInclusive<u16> = 7071..=7099;
+
 // RAPP OS process state
 struct RappOsState {
     process: Arc<Mutex<Option<Child>>>,
-    port: u16,
+    port: AtomicU16,
+    log_buffer: Arc<Mutex<VecDeque<String>>>,
+    log_readers: Mutex<Vec<JoinHandle<()>>>,
+    // The current run's rotating log file; a plain per-run handle rather than a
+    // process-global tracing subscriber, so each restart gets its own live writer.
+    log_file: Arc<Mutex<Option<std::fs::File>>>,
 }
 
 impl Default for RappOsState {
     fn default() -> Self {
         Self {
             process: Arc::new(Mutex::new(None)),
-            port: 7071,
+            port: AtomicU16::new(*RAPP_OS_PORT_RANGE.start()),
+            log_buffer: Arc::new(Mutex::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY))),
+            log_readers: Mutex::new(Vec::new()),
+            log_file: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+// Probes `range` by attempting to bind each port, returning the first free one.
+fn find_free_port(range: std::ops::RangeInclusive<u16>) -> Option<u16> {
+    range.into_iter().find(|port| TcpListener::bind(("127.0.0.1", *port)).is_ok())
+}
+
+// Merges `rapp_os_port` into the persisted ~/.rapp/config.json, leaving the rest untouched.
+fn persist_rapp_os_port(port: u16) {
+    let Some(home) = dirs::home_dir() else { return };
+    let config_path = home.join(".rapp/config.json");
+
+    let mut value: serde_json::Value = config_path
+        .exists()
+        .then(|| std::fs::read_to_string(&config_path).ok())
+        .flatten()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_else(|| serde_json::json!({}));
+
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert("rapp_os_port".to_string(), serde_json::json!(port));
+        std::fs::create_dir_all(&home.join(".rapp")).ok();
+        if let Ok(content) = serde_json::to_string_pretty(&value) {
+            std::fs::write(&config_path, content).ok();
         }
     }
 }
@@ -26,6 +71,12 @@ pub struct RappConfig {
     pub rapp_home: String,
     pub azure_configured: bool,
     pub projects: Vec<ProjectInfo>,
+    #[serde(default)]
+    pub rapp_os_port: Option<u16>,
+    #[serde(default)]
+    pub notifier: NotifierConfig,
+    #[serde(default)]
+    pub hotkey: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -61,6 +112,9 @@ fn get_config() -> Result<RappConfig, String> {
             rapp_home: home.join(".rapp").to_string_lossy().to_string(),
             azure_configured: false,
             projects: vec![],
+            rapp_os_port: None,
+            notifier: NotifierConfig::default(),
+            hotkey: None,
         })
     }
 }
@@ -76,48 +130,334 @@ fn save_config(config: RappConfig) -> Result<(), String> {
 }
 
 // RAPP Store & Hub
+
+// One entry in the RAPP Store manifest: a semver, its digest, and its primary file.
+// Only a single file per entry is supported; there is no per-file checksum to verify more.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ManifestEntry {
+    pub id: String,
+    pub version: String,
+    pub sha256: String,
+    pub path: String,
+    pub file: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StoreManifest {
+    #[serde(default)]
+    pub agents: Vec<ManifestEntry>,
+    #[serde(default)]
+    pub skills: Vec<ManifestEntry>,
+}
+
+// A single ~/.rapp/installed.json record: what's on disk right now.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstalledEntry {
+    pub id: String,
+    pub version: String,
+    pub sha256: String,
+    pub installed_at: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct InstalledRegistry {
+    #[serde(default)]
+    pub agents: std::collections::HashMap<String, InstalledEntry>,
+    #[serde(default)]
+    pub skills: std::collections::HashMap<String, InstalledEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UpdateCandidate {
+    pub id: String,
+    pub kind: String,
+    pub installed_version: String,
+    pub available_version: String,
+}
+
+const STORE_MANIFEST_URL: &str = "https://raw.githubusercontent.com/kody-w/RAPP_Store/main/manifest.json";
+
+fn epoch_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(bytes);
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn installed_registry_path(home: &std::path::Path) -> PathBuf {
+    home.join(".rapp/installed.json")
+}
+
+fn load_installed_registry(home: &std::path::Path) -> InstalledRegistry {
+    std::fs::read_to_string(installed_registry_path(home))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_installed_registry(home: &std::path::Path, registry: &InstalledRegistry) -> Result<(), String> {
+    std::fs::create_dir_all(home.join(".rapp")).map_err(|e| e.to_string())?;
+    let content = serde_json::to_string_pretty(registry).map_err(|e| e.to_string())?;
+    std::fs::write(installed_registry_path(home), content).map_err(|e| e.to_string())
+}
+
+// Appends id to the project's rapp.json `dependencies.rapp_store.<kind>` list if it isn't there yet.
+fn record_project_dependency(project_path: &str, kind: &str, id: &str) {
+    let rapp_json = PathBuf::from(project_path).join("rapp.json");
+    let Ok(content) = std::fs::read_to_string(&rapp_json) else { return };
+    let Ok(mut value) = serde_json::from_str::<serde_json::Value>(&content) else { return };
+
+    let deps = value
+        .pointer_mut("/dependencies/rapp_store")
+        .and_then(|v| v.as_object_mut());
+    if let Some(deps) = deps {
+        if let Some(list) = deps.get_mut(kind).and_then(|v| v.as_array_mut()) {
+            if !list.iter().any(|v| v.as_str() == Some(id)) {
+                list.push(serde_json::json!(id));
+            }
+        }
+    }
+
+    if let Ok(pretty) = serde_json::to_string_pretty(&value) {
+        std::fs::write(&rapp_json, pretty).ok();
+    }
+}
+
 #[tauri::command]
 async fn fetch_manifest(url: String) -> Result<String, String> {
     reqwest::get(&url).await.map_err(|e| e.to_string())?
         .text().await.map_err(|e| e.to_string())
 }
 
+async fn fetch_store_manifest() -> Result<StoreManifest, String> {
+    let content = reqwest::get(STORE_MANIFEST_URL).await.map_err(|e| e.to_string())?
+        .text().await.map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid store manifest: {}", e))
+}
+
+// Downloads entry's file into dest_dir, verifying its SHA-256 digest before the file is
+// written, then records it in ~/.rapp/installed.json.
+async fn install_manifest_entry(
+    entry: &ManifestEntry,
+    dest_dir: &std::path::Path,
+    filename: &str,
+    kind: &str,
+    project: Option<&str>,
+) -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or("No home directory")?;
+    std::fs::create_dir_all(dest_dir).map_err(|e| e.to_string())?;
+
+    let url = format!("https://raw.githubusercontent.com/kody-w/RAPP_Store/main/{}/{}", entry.path, filename);
+    let bytes = reqwest::get(&url).await.map_err(|e| e.to_string())?
+        .bytes().await.map_err(|e| e.to_string())?;
+
+    let digest = sha256_hex(&bytes);
+    if digest != entry.sha256 {
+        return Err(format!(
+            "Checksum mismatch for {} {}: expected {}, got {}",
+            entry.id, entry.version, entry.sha256, digest
+        ));
+    }
+
+    let dest_file = dest_dir.join(filename);
+    std::fs::write(&dest_file, &bytes).map_err(|e| e.to_string())?;
+
+    let mut registry = load_installed_registry(&home);
+    let installed_entry = InstalledEntry {
+        id: entry.id.clone(),
+        version: entry.version.clone(),
+        sha256: digest,
+        installed_at: epoch_secs(),
+    };
+    match kind {
+        "agents" => { registry.agents.insert(entry.id.clone(), installed_entry); }
+        "skills" => { registry.skills.insert(entry.id.clone(), installed_entry); }
+        _ => {}
+    }
+    save_installed_registry(&home, &registry)?;
+
+    if let Some(project) = project {
+        record_project_dependency(project, kind, &entry.id);
+    }
+
+    Ok(dest_file)
+}
+
 #[tauri::command]
-async fn install_agent(agent_id: String, path: String, filename: String) -> Result<InstallResult, String> {
+async fn install_agent(agent_id: String, project: Option<String>) -> Result<InstallResult, String> {
     let home = dirs::home_dir().ok_or("No home directory")?;
+    let manifest = fetch_store_manifest().await?;
+    let entry = manifest.agents.iter().find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found in store manifest", agent_id))?;
+
     let agents_dir = home.join(".rapp/agents");
-    std::fs::create_dir_all(&agents_dir).map_err(|e| e.to_string())?;
+    let agent_file = install_manifest_entry(entry, &agents_dir, &entry.file, "agents", project.as_deref()).await?;
 
-    let url = format!("https://raw.githubusercontent.com/kody-w/RAPP_Store/main/{}/{}", path, filename);
-    let content = reqwest::get(&url).await.map_err(|e| e.to_string())?
-        .text().await.map_err(|e| e.to_string())?;
+    Ok(InstallResult {
+        success: true,
+        message: format!("Installed {} {}", agent_id, entry.version),
+        path: Some(agent_file.to_string_lossy().to_string()),
+    })
+}
+
+#[tauri::command]
+async fn install_skill(skill_id: String, project: Option<String>) -> Result<InstallResult, String> {
+    let home = dirs::home_dir().ok_or("No home directory")?;
+    let manifest = fetch_store_manifest().await?;
+    let entry = manifest.skills.iter().find(|s| s.id == skill_id)
+        .ok_or_else(|| format!("Skill '{}' not found in store manifest", skill_id))?;
+
+    let skill_dir = home.join(".rapp/skills").join(&skill_id);
+    let skill_file = install_manifest_entry(entry, &skill_dir, "SKILL.md", "skills", project.as_deref()).await?;
+
+    Ok(InstallResult {
+        success: true,
+        message: format!("Installed {} {}", skill_id, entry.version),
+        path: Some(skill_file.to_string_lossy().to_string()),
+    })
+}
+
+// Diffs ~/.rapp/installed.json against the live store manifest and returns every
+// installed agent/skill whose version differs from the manifest's (no semver ordering
+// is applied, so an installed version ahead of the manifest is listed too).
+#[tauri::command]
+async fn check_updates() -> Result<Vec<UpdateCandidate>, String> {
+    let home = dirs::home_dir().ok_or("No home directory")?;
+    let registry = load_installed_registry(&home);
+    let manifest = fetch_store_manifest().await?;
+
+    let mut candidates = Vec::new();
+    for entry in &manifest.agents {
+        if let Some(installed) = registry.agents.get(&entry.id) {
+            if installed.version != entry.version {
+                candidates.push(UpdateCandidate {
+                    id: entry.id.clone(),
+                    kind: "agents".to_string(),
+                    installed_version: installed.version.clone(),
+                    available_version: entry.version.clone(),
+                });
+            }
+        }
+    }
+    for entry in &manifest.skills {
+        if let Some(installed) = registry.skills.get(&entry.id) {
+            if installed.version != entry.version {
+                candidates.push(UpdateCandidate {
+                    id: entry.id.clone(),
+                    kind: "skills".to_string(),
+                    installed_version: installed.version.clone(),
+                    available_version: entry.version.clone(),
+                });
+            }
+        }
+    }
 
-    let agent_file = agents_dir.join(&filename);
-    std::fs::write(&agent_file, &content).map_err(|e| e.to_string())?;
+    Ok(candidates)
+}
+
+// Backs up the currently installed agent under .backup/<id>/<version>/ and installs
+// the latest manifest version in its place. Use rollback_agent to undo.
+#[tauri::command]
+async fn update_agent(agent_id: String) -> Result<InstallResult, String> {
+    let result = update_agent_impl(&agent_id).await;
+
+    match &result {
+        Ok(r) => dispatch_notification(NotifyEvent {
+            event: "agent_updated".to_string(),
+            session_guid: None,
+            agents_used: vec![agent_id.clone()],
+            success: true,
+            message: r.message.clone(),
+        }),
+        Err(e) => dispatch_notification(NotifyEvent {
+            event: "agent_update_error".to_string(),
+            session_guid: None,
+            agents_used: vec![agent_id.clone()],
+            success: false,
+            message: e.clone(),
+        }),
+    }
+
+    result
+}
+
+async fn update_agent_impl(agent_id: &str) -> Result<InstallResult, String> {
+    let home = dirs::home_dir().ok_or("No home directory")?;
+    let registry = load_installed_registry(&home);
+    let installed = registry.agents.get(agent_id)
+        .ok_or_else(|| format!("Agent '{}' is not installed", agent_id))?
+        .clone();
+
+    let manifest = fetch_store_manifest().await?;
+    let entry = manifest.agents.iter().find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found in store manifest", agent_id))?;
+
+    let agents_dir = home.join(".rapp/agents");
+    let current_file = agents_dir.join(&entry.file);
+
+    if current_file.exists() {
+        let backup_dir = home.join(".rapp/agents/.backup").join(agent_id).join(&installed.version);
+        std::fs::create_dir_all(&backup_dir).map_err(|e| e.to_string())?;
+        std::fs::copy(&current_file, backup_dir.join(&entry.file)).map_err(|e| e.to_string())?;
+    }
+
+    let agent_file = install_manifest_entry(entry, &agents_dir, &entry.file, "agents", None).await?;
 
     Ok(InstallResult {
         success: true,
-        message: format!("Installed {}", agent_id),
+        message: format!("Updated {} {} -> {}", agent_id, installed.version, entry.version),
         path: Some(agent_file.to_string_lossy().to_string()),
     })
 }
 
+// Restores the most recent backup taken by update_agent, undoing a failed or unwanted update.
 #[tauri::command]
-async fn install_skill(skill_id: String, path: String) -> Result<InstallResult, String> {
+fn rollback_agent(agent_id: String) -> Result<InstallResult, String> {
     let home = dirs::home_dir().ok_or("No home directory")?;
-    let skill_dir = home.join(".rapp/skills").join(&skill_id);
-    std::fs::create_dir_all(&skill_dir).map_err(|e| e.to_string())?;
+    let backup_root = home.join(".rapp/agents/.backup").join(&agent_id);
 
-    let url = format!("https://raw.githubusercontent.com/kody-w/RAPP_Store/main/{}/SKILL.md", path);
-    let content = reqwest::get(&url).await.map_err(|e| e.to_string())?
-        .text().await.map_err(|e| e.to_string())?;
+    let versions = std::fs::read_dir(&backup_root).map_err(|e| e.to_string())?;
+    let latest_version = versions
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .max_by_key(|e| e.metadata().and_then(|m| m.modified()).ok())
+        .ok_or_else(|| format!("No backup found for agent '{}'", agent_id))?;
 
-    std::fs::write(skill_dir.join("SKILL.md"), &content).map_err(|e| e.to_string())?;
+    let version_name = latest_version.file_name().to_string_lossy().to_string();
+    let agents_dir = home.join(".rapp/agents");
+
+    let mut restored_sha256 = None;
+    for file in std::fs::read_dir(latest_version.path()).map_err(|e| e.to_string())? {
+        let file = file.map_err(|e| e.to_string())?;
+        let dest = agents_dir.join(file.file_name());
+        std::fs::copy(file.path(), &dest).map_err(|e| e.to_string())?;
+        if restored_sha256.is_none() {
+            if let Ok(bytes) = std::fs::read(&dest) {
+                restored_sha256 = Some(sha256_hex(&bytes));
+            }
+        }
+    }
+
+    let mut registry = load_installed_registry(&home);
+    if let Some(installed) = registry.agents.get_mut(&agent_id) {
+        installed.version = version_name.clone();
+        installed.installed_at = epoch_secs();
+        if let Some(sha256) = restored_sha256 {
+            installed.sha256 = sha256;
+        }
+    }
+    save_installed_registry(&home, &registry)?;
 
     Ok(InstallResult {
         success: true,
-        message: format!("Installed {}", skill_id),
-        path: Some(skill_dir.to_string_lossy().to_string()),
+        message: format!("Rolled back {} to {}", agent_id, version_name),
+        path: Some(agents_dir.to_string_lossy().to_string()),
     })
 }
 
@@ -198,11 +538,176 @@ fn check_prerequisites() -> serde_json::Value {
     })
 }
 
+// ============ Notifications ============
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyEvent {
+    pub event: String,
+    pub session_guid: Option<String>,
+    pub agents_used: Vec<String>,
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NotifierConfig {
+    #[serde(default)]
+    pub desktop_enabled: bool,
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    #[serde(default)]
+    pub smtp: Option<SmtpConfig>,
+}
+
+trait Notifier: Send {
+    fn id(&self) -> &'static str;
+    fn notify(&self, event: &NotifyEvent) -> Result<(), String>;
+}
+
+struct DesktopNotifier;
+
+impl Notifier for DesktopNotifier {
+    fn id(&self) -> &'static str {
+        "desktop"
+    }
+
+    fn notify(&self, event: &NotifyEvent) -> Result<(), String> {
+        notify_rust::Notification::new()
+            .summary(&format!("RAPP Desktop - {}", event.event))
+            .body(&event.message)
+            .show()
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+struct WebhookNotifier {
+    url: String,
+}
+
+impl Notifier for WebhookNotifier {
+    fn id(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn notify(&self, event: &NotifyEvent) -> Result<(), String> {
+        reqwest::blocking::Client::new()
+            .post(&self.url)
+            .json(event)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+struct EmailNotifier {
+    smtp: SmtpConfig,
+}
+
+impl Notifier for EmailNotifier {
+    fn id(&self) -> &'static str {
+        "email"
+    }
+
+    fn notify(&self, event: &NotifyEvent) -> Result<(), String> {
+        use lettre::transport::smtp::authentication::Credentials;
+        use lettre::{Message, SmtpTransport, Transport};
+
+        let email = Message::builder()
+            .from(self.smtp.from.parse().map_err(|e| format!("Invalid from address: {}", e))?)
+            .to(self.smtp.to.parse().map_err(|e| format!("Invalid to address: {}", e))?)
+            .subject(format!("RAPP Desktop - {}", event.event))
+            .body(event.message.clone())
+            .map_err(|e| e.to_string())?;
+
+        // Port 465 is implicit TLS; anything else (587, 25, ...) expects STARTTLS.
+        let builder = if self.smtp.port == 465 {
+            SmtpTransport::relay(&self.smtp.host)
+        } else {
+            SmtpTransport::starttls_relay(&self.smtp.host)
+        };
+        let mailer = builder
+            .map_err(|e| e.to_string())?
+            .port(self.smtp.port)
+            .credentials(Credentials::new(self.smtp.username.clone(), self.smtp.password.clone()))
+            .build();
+
+        mailer.send(&email).map(|_| ()).map_err(|e| e.to_string())
+    }
+}
+
+fn build_notifiers(config: &NotifierConfig) -> Vec<Box<dyn Notifier>> {
+    let mut sinks: Vec<Box<dyn Notifier>> = Vec::new();
+
+    if config.desktop_enabled {
+        sinks.push(Box::new(DesktopNotifier));
+    }
+    if let Some(url) = &config.webhook_url {
+        sinks.push(Box::new(WebhookNotifier { url: url.clone() }));
+    }
+    if let Some(smtp) = &config.smtp {
+        sinks.push(Box::new(EmailNotifier { smtp: smtp.clone() }));
+    }
+
+    sinks
+}
+
+// Fans event out to every enabled sink on a background thread; sink failures are
+// logged, not propagated, so one broken webhook doesn't stop the others from firing.
+fn dispatch_notification(event: NotifyEvent) {
+    let config = get_config().map(|c| c.notifier).unwrap_or_default();
+    let sinks = build_notifiers(&config);
+    if sinks.is_empty() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        for sink in sinks {
+            if let Err(e) = sink.notify(&event) {
+                tracing::warn!("notifier '{}' failed: {}", sink.id(), e);
+            }
+        }
+    });
+}
+
+// Fires sink_id with a canned test event so users can verify their notifier config
+// without waiting on a real run.
+#[tauri::command]
+fn test_notifier(sink_id: String) -> Result<(), String> {
+    let config = get_config()?.notifier;
+    let sinks = build_notifiers(&config);
+    let sink = sinks
+        .into_iter()
+        .find(|s| s.id() == sink_id)
+        .ok_or_else(|| format!("Sink '{}' is not enabled", sink_id))?;
+
+    sink.notify(&NotifyEvent {
+        event: "test".to_string(),
+        session_guid: None,
+        agents_used: vec![],
+        success: true,
+        message: "This is a test notification from RAPP Desktop.".to_string(),
+    })
+}
+
 // ============ RAPP OS Integration ============
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RappOsStatus {
     pub running: bool,
+    // True when the process is alive but its port isn't accepting connections yet.
+    pub starting: bool,
     pub port: u16,
     pub endpoint: String,
 }
@@ -226,8 +731,71 @@ pub struct ChatResponse {
     pub context_guid: String,
 }
 
-#[tauri::command]
-fn start_rapp_os(state: State<RappOsState>) -> Result<RappOsStatus, String> {
+// Appends a line to the in-memory tail buffer, dropping the oldest once capacity is hit.
+fn push_log_line(buffer: &Arc<Mutex<VecDeque<String>>>, stream: &str, line: &str) {
+    if let Ok(mut buf) = buffer.lock() {
+        if buf.len() >= LOG_BUFFER_CAPACITY {
+            buf.pop_front();
+        }
+        buf.push_back(format!("[{}] {}", stream, line));
+    }
+}
+
+// Appends a line to this run's rotating log file, if one is open.
+fn write_rapp_os_log_line(log_file: &Arc<Mutex<Option<std::fs::File>>>, stream: &str, line: &str) {
+    use std::io::Write;
+    if let Ok(mut guard) = log_file.lock() {
+        if let Some(file) = guard.as_mut() {
+            writeln!(file, "[{}] {}", stream, line).ok();
+        }
+    }
+}
+
+// Opens ~/.rapp/logs/rapp_os-<timestamp>.log for this run. A plain per-run file handle
+// rather than a process-global tracing subscriber, since tracing_subscriber::fmt's
+// try_init() only succeeds once per process - a second call on restart would silently
+// fail to install while still replacing the first run's writer guard, killing the log.
+fn init_rapp_os_log_file() -> Option<std::fs::File> {
+    let home = dirs::home_dir()?;
+    let logs_dir = home.join(".rapp/logs");
+    std::fs::create_dir_all(&logs_dir).ok()?;
+    let path = logs_dir.join(format!("rapp_os-{}.log", epoch_secs()));
+    std::fs::OpenOptions::new().create(true).append(true).open(&path).ok()
+}
+
+fn rapp_os_pid_file(home: &std::path::Path) -> PathBuf {
+    home.join(".rapp/rapp_os.pid")
+}
+
+// Persists the spawned PID/port so a separate CLI invocation (rapp stop/rapp status)
+// can find the process started by a previous one.
+fn write_rapp_os_pid_file(pid: u32, port: u16) {
+    if let Some(home) = dirs::home_dir() {
+        std::fs::create_dir_all(home.join(".rapp")).ok();
+        std::fs::write(rapp_os_pid_file(&home), format!("{} {}", pid, port)).ok();
+    }
+}
+
+fn read_rapp_os_pid_file() -> Option<(u32, u16)> {
+    let home = dirs::home_dir()?;
+    let content = std::fs::read_to_string(rapp_os_pid_file(&home)).ok()?;
+    let mut parts = content.split_whitespace();
+    let pid: u32 = parts.next()?.parse().ok()?;
+    let port: u16 = parts.next()?.parse().ok()?;
+    Some((pid, port))
+}
+
+fn remove_rapp_os_pid_file() {
+    if let Some(home) = dirs::home_dir() {
+        std::fs::remove_file(rapp_os_pid_file(&home)).ok();
+    }
+}
+
+// Core of start_rapp_os, factored out so the CLI dispatcher can drive it without a Tauri
+// AppHandle. app is None in CLI mode: since the CLI process exits right after `rapp start`
+// returns, in-process reader threads would die with it and break the child's pipe, so CLI
+// mode redirects the child's stdio straight to the log file instead of relaying it.
+fn start_rapp_os_impl(app: Option<tauri::AppHandle>, state: &RappOsState) -> Result<RappOsStatus, String> {
     let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
 
     // Check if already running
@@ -235,10 +803,12 @@ fn start_rapp_os(state: State<RappOsState>) -> Result<RappOsStatus, String> {
         match child.try_wait() {
             Ok(None) => {
                 // Still running
+                let port = state.port.load(Ordering::SeqCst);
                 return Ok(RappOsStatus {
                     running: true,
-                    port: state.port,
-                    endpoint: format!("http://127.0.0.1:{}/api/rapp", state.port),
+                    starting: false,
+                    port,
+                    endpoint: format!("http://127.0.0.1:{}/api/rapp", port),
                 });
             }
             _ => {
@@ -248,6 +818,20 @@ fn start_rapp_os(state: State<RappOsState>) -> Result<RappOsStatus, String> {
         }
     }
 
+    // No in-process handle (e.g. a fresh CLI invocation) - a separate invocation may
+    // still have RAPP OS running, so check the pid file before spawning a duplicate.
+    if let Some((pid, pidfile_port)) = read_rapp_os_pid_file() {
+        if pid_is_listening(pid, pidfile_port) {
+            state.port.store(pidfile_port, Ordering::SeqCst);
+            return Ok(RappOsStatus {
+                running: true,
+                starting: false,
+                port: pidfile_port,
+                endpoint: format!("http://127.0.0.1:{}/api/rapp", pidfile_port),
+            });
+        }
+    }
+
     // Find rapp_os.py path
     let home = dirs::home_dir().ok_or("No home directory")?;
     let rapp_os_paths = vec![
@@ -264,16 +848,74 @@ fn start_rapp_os(state: State<RappOsState>) -> Result<RappOsStatus, String> {
 
     let rapp_os_path = rapp_os_path.unwrap();
 
-    // Start RAPP OS
-    let child = Command::new("python3")
-        .arg(rapp_os_path)
-        .arg("--port")
-        .arg(state.port.to_string())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("Failed to start RAPP OS: {}", e))?;
+    // Probe for a free port rather than trusting the last one used; a prior RAPP OS
+    // run (or an unrelated service) may still be bound to it.
+    let port = find_free_port(RAPP_OS_PORT_RANGE)
+        .ok_or_else(|| format!("No free port available in range {}-{}", RAPP_OS_PORT_RANGE.start(), RAPP_OS_PORT_RANGE.end()))?;
+    state.port.store(port, Ordering::SeqCst);
+    persist_rapp_os_port(port);
+
+    // Start RAPP OS. CLI mode (no AppHandle) redirects stdio straight to a log file so the
+    // child's output survives this process exiting; GUI mode pipes it through reader threads
+    // so lines can be buffered and emitted as events.
+    let mut command = Command::new("python3");
+    command.arg(rapp_os_path).arg("--port").arg(port.to_string());
+
+    let cli_log_file = if app.is_none() {
+        let logs_dir = home.join(".rapp/logs");
+        std::fs::create_dir_all(&logs_dir).map_err(|e| e.to_string())?;
+        let path = logs_dir.join(format!("rapp_os-{}.log", epoch_secs()));
+        Some(std::fs::OpenOptions::new().create(true).append(true).open(&path).map_err(|e| e.to_string())?)
+    } else {
+        None
+    };
+
+    if let Some(file) = &cli_log_file {
+        command.stdout(Stdio::from(file.try_clone().map_err(|e| e.to_string())?));
+        command.stderr(Stdio::from(file.try_clone().map_err(|e| e.to_string())?));
+    } else {
+        command.stdout(Stdio::piped());
+        command.stderr(Stdio::piped());
+    }
+
+    let mut child = command.spawn().map_err(|e| format!("Failed to start RAPP OS: {}", e))?;
+
+    if let Some(app) = app {
+        if let Ok(mut log_file) = state.log_file.lock() {
+            *log_file = init_rapp_os_log_file();
+        }
+
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        let mut readers = state.log_readers.lock().map_err(|e| e.to_string())?;
+
+        if let Some(stdout) = stdout {
+            let buffer = state.log_buffer.clone();
+            let log_file = state.log_file.clone();
+            let app = app.clone();
+            readers.push(std::thread::spawn(move || {
+                for line in BufReader::new(stdout).lines().flatten() {
+                    write_rapp_os_log_line(&log_file, "stdout", &line);
+                    push_log_line(&buffer, "stdout", &line);
+                    app.emit_all("rapp-os-log", serde_json::json!({ "stream": "stdout", "line": line })).ok();
+                }
+            }));
+        }
+
+        if let Some(stderr) = stderr {
+            let buffer = state.log_buffer.clone();
+            let log_file = state.log_file.clone();
+            readers.push(std::thread::spawn(move || {
+                for line in BufReader::new(stderr).lines().flatten() {
+                    write_rapp_os_log_line(&log_file, "stderr", &line);
+                    push_log_line(&buffer, "stderr", &line);
+                    app.emit_all("rapp-os-log", serde_json::json!({ "stream": "stderr", "line": line })).ok();
+                }
+            }));
+        }
+    }
 
+    write_rapp_os_pid_file(child.id(), port);
     *process_guard = Some(child);
 
     // Wait a moment for server to start
@@ -281,50 +923,134 @@ fn start_rapp_os(state: State<RappOsState>) -> Result<RappOsStatus, String> {
 
     Ok(RappOsStatus {
         running: true,
-        port: state.port,
-        endpoint: format!("http://127.0.0.1:{}/api/rapp", state.port),
+        starting: false,
+        port,
+        endpoint: format!("http://127.0.0.1:{}/api/rapp", port),
     })
 }
 
 #[tauri::command]
-fn stop_rapp_os(state: State<RappOsState>) -> Result<RappOsStatus, String> {
+fn start_rapp_os(app: tauri::AppHandle, state: State<RappOsState>) -> Result<RappOsStatus, String> {
+    start_rapp_os_impl(Some(app), &state)
+}
+
+// Core of stop_rapp_os. Falls back to the ~/.rapp/rapp_os.pid file when state has no
+// in-process handle, which is the normal case for a standalone CLI invocation.
+fn stop_rapp_os_impl(state: &RappOsState) -> Result<RappOsStatus, String> {
     let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
 
     if let Some(ref mut child) = *process_guard {
         child.kill().ok();
         child.wait().ok();
+    } else if let Some((pid, _port)) = read_rapp_os_pid_file() {
+        #[cfg(unix)]
+        { Command::new("kill").arg(pid.to_string()).status().ok(); }
+        #[cfg(windows)]
+        { Command::new("taskkill").args(["/PID", &pid.to_string(), "/F"]).status().ok(); }
     }
 
     *process_guard = None;
+    remove_rapp_os_pid_file();
+
+    // stdout/stderr EOF once the child exits, so the reader threads are about to finish.
+    if let Ok(mut readers) = state.log_readers.lock() {
+        for handle in readers.drain(..) {
+            handle.join().ok();
+        }
+    }
 
     Ok(RappOsStatus {
         running: false,
-        port: state.port,
+        starting: false,
+        port: state.port.load(Ordering::SeqCst),
         endpoint: String::new(),
     })
 }
 
 #[tauri::command]
-fn get_rapp_os_status(state: State<RappOsState>) -> RappOsStatus {
-    let process_guard = state.process.lock().ok();
-
-    let running = process_guard.as_ref()
-        .and_then(|guard| guard.as_ref())
-        .and_then(|child| {
-            // Can't call try_wait on immutable ref, so we check port instead
-            reqwest::blocking::Client::new()
-                .get(format!("http://127.0.0.1:{}/health", state.port))
-                .timeout(std::time::Duration::from_millis(500))
-                .send()
-                .ok()
-        })
-        .is_some();
+fn stop_rapp_os(state: State<RappOsState>) -> Result<RappOsStatus, String> {
+    stop_rapp_os_impl(&state)
+}
+
+// Returns the last `tail` captured RAPP OS stdout/stderr lines, oldest first.
+#[tauri::command]
+fn get_rapp_os_logs(tail: usize, state: State<RappOsState>) -> Vec<String> {
+    let buffer = match state.log_buffer.lock() {
+        Ok(buffer) => buffer,
+        Err(_) => return Vec::new(),
+    };
+    buffer.iter().rev().take(tail).rev().cloned().collect()
+}
+
+#[tauri::command]
+fn clear_logs(state: State<RappOsState>) {
+    if let Ok(mut buffer) = state.log_buffer.lock() {
+        buffer.clear();
+    }
+}
+
+// Checks whether any TCP socket owned by `pid` is listening on `port`.
+fn pid_is_listening(pid: u32, port: u16) -> bool {
+    use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+
+    let sockets = match get_sockets_info(AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6, ProtocolFlags::TCP) {
+        Ok(sockets) => sockets,
+        Err(_) => return false,
+    };
+
+    sockets.iter().any(|socket| {
+        let local_port = match &socket.protocol_socket_info {
+            ProtocolSocketInfo::Tcp(tcp) => tcp.local_port,
+            _ => return false,
+        };
+        local_port == port && socket.associated_pids.contains(&pid)
+    })
+}
+
+fn get_rapp_os_status_impl(state: &RappOsState) -> RappOsStatus {
+    let port = state.port.load(Ordering::SeqCst);
+    let mut process_guard = match state.process.lock() {
+        Ok(guard) => guard,
+        Err(_) => return RappOsStatus { running: false, starting: false, port, endpoint: String::new() },
+    };
+
+    // No in-process handle (e.g. a fresh CLI invocation) - fall back to the PID file
+    // left by whichever invocation called `start_rapp_os`. Reaching this point means the
+    // process is confirmed alive (try_wait still pending, or the pid file's process still
+    // exists); the only thing left to determine is whether it's listening yet.
+    let (pid, port) = match process_guard.as_mut() {
+        Some(child) => {
+            if !matches!(child.try_wait(), Ok(None)) {
+                return RappOsStatus { running: false, starting: false, port, endpoint: String::new() };
+            }
+            (child.id(), port)
+        }
+        None => match read_rapp_os_pid_file() {
+            Some((pid, pidfile_port)) => {
+                #[cfg(unix)]
+                let alive = Command::new("kill").args(["-0", &pid.to_string()]).status().map(|s| s.success()).unwrap_or(false);
+                #[cfg(windows)]
+                let alive = Command::new("tasklist").args(["/FI", &format!("PID eq {}", pid), "/NH"]).output()
+                    .map(|o| String::from_utf8_lossy(&o.stdout).contains(&pid.to_string())).unwrap_or(false);
+                #[cfg(not(any(unix, windows)))]
+                let alive = true;
+                if !alive {
+                    return RappOsStatus { running: false, starting: false, port: pidfile_port, endpoint: String::new() };
+                }
+                (pid, pidfile_port)
+            }
+            None => return RappOsStatus { running: false, starting: false, port, endpoint: String::new() },
+        },
+    };
+
+    let listening = pid_is_listening(pid, port);
 
     RappOsStatus {
-        running,
-        port: state.port,
-        endpoint: if running {
-            format!("http://127.0.0.1:{}/api/rapp", state.port)
+        running: listening,
+        starting: !listening,
+        port,
+        endpoint: if listening {
+            format!("http://127.0.0.1:{}/api/rapp", port)
         } else {
             String::new()
         },
@@ -332,8 +1058,12 @@ fn get_rapp_os_status(state: State<RappOsState>) -> RappOsStatus {
 }
 
 #[tauri::command]
-async fn chat_with_rapp(request: ChatRequest, state: State<'_, RappOsState>) -> Result<ChatResponse, String> {
-    let endpoint = format!("http://127.0.0.1:{}/api/rapp", state.port);
+fn get_rapp_os_status(state: State<RappOsState>) -> RappOsStatus {
+    get_rapp_os_status_impl(&state)
+}
+
+async fn chat_with_rapp_impl(request: ChatRequest, port: u16) -> Result<ChatResponse, String> {
+    let endpoint = format!("http://127.0.0.1:{}/api/rapp", port);
 
     let body = serde_json::json!({
         "user_input": request.user_input,
@@ -360,9 +1090,173 @@ async fn chat_with_rapp(request: ChatRequest, state: State<'_, RappOsState>) ->
         .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
+#[tauri::command]
+async fn chat_with_rapp(request: ChatRequest, state: State<'_, RappOsState>) -> Result<ChatResponse, String> {
+    let session_guid = request.session_guid.clone();
+    let result = chat_with_rapp_impl(request, state.port.load(Ordering::SeqCst)).await;
+
+    match &result {
+        Ok(response) => dispatch_notification(NotifyEvent {
+            event: "chat_complete".to_string(),
+            session_guid: Some(response.session_guid.clone()),
+            agents_used: response.agents_used.clone(),
+            success: true,
+            message: "Chat completed".to_string(),
+        }),
+        Err(e) => dispatch_notification(NotifyEvent {
+            event: "chat_error".to_string(),
+            session_guid,
+            agents_used: vec![],
+            success: false,
+            message: e.clone(),
+        }),
+    }
+
+    result
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatStreamChunk {
+    pub session_guid: String,
+    pub delta: String,
+    pub agent_logs: Vec<String>,
+}
+
+// Reads the RAPP OS SSE stream to completion, emitting rapp-chat-chunk events per delta
+// and returning the assembled ChatResponse.
+async fn chat_with_rapp_stream_impl(
+    request: ChatRequest,
+    port: u16,
+    app: &tauri::AppHandle,
+) -> Result<ChatResponse, String> {
+    use futures_util::StreamExt;
+
+    let endpoint = format!("http://127.0.0.1:{}/api/rapp/stream", port);
+    let mut session_guid = request.session_guid.clone().unwrap_or_default();
+    let mut context_guid = request.context_guid.clone().unwrap_or_else(|| "default".to_string());
+
+    let body = serde_json::json!({
+        "user_input": request.user_input,
+        "user_guid": request.user_guid.unwrap_or_else(|| "desktop".to_string()),
+        "session_guid": session_guid,
+        "context_guid": context_guid,
+        "conversation_history": request.conversation_history.unwrap_or_default(),
+        "stream": true,
+    });
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(&endpoint)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to connect to RAPP OS stream: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("RAPP OS stream error: {}", response.status()));
+    }
+
+    let mut stream = response.bytes_stream();
+    let mut buf = String::new();
+    let mut full_response = String::new();
+    let mut agent_logs: Vec<String> = Vec::new();
+    let mut agents_used: Vec<String> = Vec::new();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Stream read error: {}", e))?;
+        buf.push_str(&String::from_utf8_lossy(&chunk));
+
+        while let Some(pos) = buf.find('\n') {
+            let line: String = buf.drain(..=pos).collect();
+            let line = line.trim();
+            let payload = line.strip_prefix("data:").map(str::trim).unwrap_or(line);
+            if payload.is_empty() {
+                continue;
+            }
+
+            let event: serde_json::Value = match serde_json::from_str(payload) {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+
+            let delta = event.get("delta").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+            if let Some(logs) = event.get("agent_logs").and_then(|v| v.as_array()) {
+                agent_logs = logs.iter().filter_map(|v| v.as_str().map(String::from)).collect();
+            }
+            if let Some(used) = event.get("agents_used").and_then(|v| v.as_array()) {
+                agents_used = used.iter().filter_map(|v| v.as_str().map(String::from)).collect();
+            }
+            if let Some(guid) = event.get("session_guid").and_then(|v| v.as_str()) {
+                session_guid = guid.to_string();
+            }
+            if let Some(guid) = event.get("context_guid").and_then(|v| v.as_str()) {
+                context_guid = guid.to_string();
+            }
+
+            full_response.push_str(&delta);
+
+            app.emit_all(
+                "rapp-chat-chunk",
+                ChatStreamChunk {
+                    session_guid: session_guid.clone(),
+                    delta,
+                    agent_logs: agent_logs.clone(),
+                },
+            )
+            .ok();
+        }
+    }
+
+    Ok(ChatResponse {
+        response: full_response,
+        voice_response: None,
+        agent_logs,
+        agents_used,
+        session_guid,
+        context_guid,
+    })
+}
+
+// Streams a chat turn over the RAPP OS SSE endpoint, emitting rapp-chat-chunk events as
+// deltas arrive and a final rapp-chat-done (or rapp-chat-error) event.
+#[tauri::command]
+async fn chat_with_rapp_stream(
+    request: ChatRequest,
+    state: State<'_, RappOsState>,
+    app: tauri::AppHandle,
+) -> Result<(), String> {
+    let session_guid = request.session_guid.clone();
+    let result = chat_with_rapp_stream_impl(request, state.port.load(Ordering::SeqCst), &app).await;
+
+    match &result {
+        Ok(final_response) => {
+            app.emit_all("rapp-chat-done", final_response).ok();
+            dispatch_notification(NotifyEvent {
+                event: "chat_complete".to_string(),
+                session_guid: Some(final_response.session_guid.clone()),
+                agents_used: final_response.agents_used.clone(),
+                success: true,
+                message: "Chat completed".to_string(),
+            });
+        }
+        Err(e) => {
+            app.emit_all("rapp-chat-error", serde_json::json!({ "session_guid": session_guid, "message": e })).ok();
+            dispatch_notification(NotifyEvent {
+                event: "chat_error".to_string(),
+                session_guid,
+                agents_used: vec![],
+                success: false,
+                message: e.clone(),
+            });
+        }
+    }
+
+    result.map(|_| ())
+}
+
 #[tauri::command]
 async fn get_agents(state: State<'_, RappOsState>) -> Result<serde_json::Value, String> {
-    let endpoint = format!("http://127.0.0.1:{}/agents", state.port);
+    let endpoint = format!("http://127.0.0.1:{}/agents", state.port.load(Ordering::SeqCst));
 
     let response = reqwest::get(&endpoint)
         .await
@@ -375,7 +1269,7 @@ async fn get_agents(state: State<'_, RappOsState>) -> Result<serde_json::Value,
 
 #[tauri::command]
 async fn get_contexts(state: State<'_, RappOsState>) -> Result<serde_json::Value, String> {
-    let endpoint = format!("http://127.0.0.1:{}/contexts", state.port);
+    let endpoint = format!("http://127.0.0.1:{}/contexts", state.port.load(Ordering::SeqCst));
 
     let response = reqwest::get(&endpoint)
         .await
@@ -388,7 +1282,7 @@ async fn get_contexts(state: State<'_, RappOsState>) -> Result<serde_json::Value
 
 #[tauri::command]
 async fn reload_rapp_os(state: State<'_, RappOsState>) -> Result<serde_json::Value, String> {
-    let endpoint = format!("http://127.0.0.1:{}/reload", state.port);
+    let endpoint = format!("http://127.0.0.1:{}/reload", state.port.load(Ordering::SeqCst));
 
     let response = reqwest::get(&endpoint)
         .await
@@ -399,7 +1293,188 @@ async fn reload_rapp_os(state: State<'_, RappOsState>) -> Result<serde_json::Val
         .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
+// ============ Global Hotkey ============
+
+const DEFAULT_HOTKEY: &str = "CmdOrCtrl+Shift+Space";
+
+// Registers chord as the global summon hotkey, replacing whatever was registered before.
+// On trigger, brings the main window to the foreground and asks the frontend to focus
+// the prompt box via a focus-chat-input event.
+fn register_hotkey(app: &tauri::AppHandle, chord: &str) -> Result<(), String> {
+    use tauri::GlobalShortcutManager;
+
+    let mut manager = app.global_shortcut_manager();
+    manager.unregister_all().map_err(|e| e.to_string())?;
+
+    let app = app.clone();
+    manager
+        .register(chord, move || {
+            if let Some(window) = app.get_window("main") {
+                window.show().ok();
+                window.unminimize().ok();
+                window.set_focus().ok();
+                window.emit("focus-chat-input", ()).ok();
+            }
+        })
+        .map_err(|e| format!("Failed to register hotkey '{}': {}", chord, e))
+}
+
+// Re-registers the global shortcut at runtime and persists it to RappConfig. If chord
+// is invalid or already claimed, the previous chord is re-registered so the user isn't
+// left with no working hotkey, and an error is returned instead of panicking.
+#[tauri::command]
+fn set_hotkey(app: tauri::AppHandle, chord: String) -> Result<(), String> {
+    // Falls back to DEFAULT_HOTKEY when the user never called set_hotkey before, since
+    // that's the chord actually registered at startup (see main()'s setup closure).
+    let previous = get_config()?.hotkey.unwrap_or_else(|| DEFAULT_HOTKEY.to_string());
+
+    if let Err(e) = register_hotkey(&app, &chord) {
+        register_hotkey(&app, &previous).ok();
+        return Err(e);
+    }
+
+    let mut config = get_config()?;
+    config.hotkey = Some(chord);
+    save_config(config)
+}
+
+#[tauri::command]
+fn clear_hotkey(app: tauri::AppHandle) -> Result<(), String> {
+    use tauri::GlobalShortcutManager;
+    app.global_shortcut_manager().unregister_all().map_err(|e| e.to_string())?;
+
+    let mut config = get_config()?;
+    config.hotkey = None;
+    save_config(config)
+}
+
+// ============ Headless CLI ============
+//
+// `rapp <subcommand>` drives the same code as the Tauri commands above (their `_impl`
+// helpers for RAPP OS lifecycle and chat, the command functions themselves otherwise),
+// so scripting/CI/remote usage stays in lockstep with the GUI without its own copy of
+// the RAPP OS lifecycle logic. No subcommand falls through to the normal GUI launch.
+
+#[derive(clap::Parser)]
+#[command(name = "rapp", about = "RAPP Desktop", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(clap::Subcommand)]
+enum CliCommand {
+    /// Start the RAPP OS backend
+    Start,
+    /// Stop the RAPP OS backend
+    Stop,
+    /// Show RAPP OS status
+    Status,
+    /// Send a single chat prompt to RAPP OS
+    Chat {
+        prompt: String,
+        #[arg(long)]
+        session: Option<String>,
+    },
+    /// Install an agent or skill from the RAPP Store
+    Install {
+        #[command(subcommand)]
+        kind: CliInstallKind,
+    },
+    /// Manage local projects
+    Projects {
+        #[command(subcommand)]
+        action: CliProjectsAction,
+    },
+    /// Check local prerequisites (python3, git, az)
+    Doctor,
+}
+
+#[derive(clap::Subcommand)]
+enum CliInstallKind {
+    Agent { id: String },
+    Skill { id: String },
+}
+
+#[derive(clap::Subcommand)]
+enum CliProjectsAction {
+    List,
+    Create { name: String },
+}
+
+fn run_cli(command: CliCommand) {
+    let rt = tokio::runtime::Runtime::new().expect("failed to start async runtime");
+
+    match command {
+        CliCommand::Start => {
+            let state = RappOsState::default();
+            match start_rapp_os_impl(None, &state) {
+                Ok(status) => println!("RAPP OS started on port {} ({})", status.port, status.endpoint),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        CliCommand::Stop => {
+            let state = RappOsState::default();
+            match stop_rapp_os_impl(&state) {
+                Ok(_) => println!("RAPP OS stopped"),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        CliCommand::Status => {
+            let status = get_rapp_os_status_impl(&RappOsState::default());
+            println!("{}", serde_json::to_string_pretty(&status).unwrap_or_default());
+        }
+        CliCommand::Chat { prompt, session } => {
+            let port = read_rapp_os_pid_file().map(|(_, port)| port).unwrap_or(*RAPP_OS_PORT_RANGE.start());
+            let request = ChatRequest {
+                user_input: prompt,
+                user_guid: Some("cli".to_string()),
+                session_guid: session,
+                context_guid: None,
+                conversation_history: None,
+            };
+            rt.block_on(async {
+                match chat_with_rapp_impl(request, port).await {
+                    Ok(response) => println!("{}", response.response),
+                    Err(e) => eprintln!("Error: {}", e),
+                }
+            });
+        }
+        CliCommand::Install { kind } => rt.block_on(async {
+            let result = match kind {
+                CliInstallKind::Agent { id } => install_agent(id, None).await,
+                CliInstallKind::Skill { id } => install_skill(id, None).await,
+            };
+            match result {
+                Ok(r) => println!("{}", r.message),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }),
+        CliCommand::Projects { action } => match action {
+            CliProjectsAction::List => {
+                for project in list_projects() {
+                    println!("{}\t{}", project.name, project.path);
+                }
+            }
+            CliProjectsAction::Create { name } => match create_project(name) {
+                Ok(r) => println!("{}", r.message),
+                Err(e) => eprintln!("Error: {}", e),
+            },
+        },
+        CliCommand::Doctor => {
+            println!("{}", serde_json::to_string_pretty(&check_prerequisites()).unwrap_or_default());
+        }
+    }
+}
+
 fn main() {
+    use clap::Parser;
+    let cli = Cli::parse();
+    if let Some(command) = cli.command {
+        run_cli(command);
+        return;
+    }
+
     tauri::Builder::default()
         .manage(RappOsState::default())
         .setup(|app| {
@@ -411,6 +1486,12 @@ fn main() {
                 std::fs::create_dir_all(rapp.join("contexts")).ok();
                 std::fs::create_dir_all(rapp.join("memory")).ok();
             }
+
+            let chord = get_config().ok().and_then(|c| c.hotkey).unwrap_or_else(|| DEFAULT_HOTKEY.to_string());
+            if let Err(e) = register_hotkey(&app.handle(), &chord) {
+                tracing::warn!("Failed to register global hotkey '{}': {}", chord, e);
+            }
+
             Ok(())
         })
         .on_window_event(|event| {
@@ -432,11 +1513,17 @@ fn main() {
             get_rapp_home, get_config, save_config,
             // Store & Hub
             fetch_manifest, install_agent, install_skill, clone_implementation,
+            check_updates, update_agent, rollback_agent,
             // Projects
             create_project, list_projects, open_path, check_prerequisites,
+            // Notifications
+            test_notifier,
+            // Hotkey
+            set_hotkey, clear_hotkey,
             // RAPP OS
             start_rapp_os, stop_rapp_os, get_rapp_os_status,
-            chat_with_rapp, get_agents, get_contexts, reload_rapp_os,
+            get_rapp_os_logs, clear_logs,
+            chat_with_rapp, chat_with_rapp_stream, get_agents, get_contexts, reload_rapp_os,
         ])
         .run(tauri::generate_context!())
         .expect("error running RAPP Desktop");